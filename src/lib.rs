@@ -40,43 +40,657 @@ use ::tracing::Level;
 /// assert_eq!(MyEnum::Foo.as_static_str(), "foo");
 /// assert_eq!(NoDisplayEnum::ENUM_VARIANTS.len(), 3);
 /// ```
+///
+/// Instead of spelling out every string, the `@rename_all` mode derives it from the variant
+/// identifier, borrowing the `rename_all` idea from serde/strum:
+/// ```
+/// use vlk_tracing_subscriber::stringable_enum;
+///
+/// pub enum Verbosity {
+///     Quiet,
+///     Normal,
+///     VeryLoud,
+/// }
+///
+/// stringable_enum! {
+///     @rename_all kebab_case
+///     Verbosity {
+///         Quiet,
+///         Normal,
+///         // A single variant can still pin its own string; the rest are generated.
+///         VeryLoud = "loud",
+///     }
+/// }
+///
+/// assert_eq!(Verbosity::VeryLoud.as_static_str(), "loud");
+/// assert_eq!(Verbosity::Quiet.as_static_str(), "quiet");
+/// assert_eq!(Verbosity::ENUM_VARIANT_STRINGS, ["quiet", "normal", "loud"]);
+/// ```
+/// The supported cases are `snake_case`, `kebab_case`, `camelCase`, `PascalCase`, and
+/// `SCREAMING_SNAKE_CASE`. Each identifier is split on every transition from a lowercase letter
+/// or digit to an uppercase letter (and on existing underscores), so `VeryLoud` becomes
+/// `very_loud`/`very-loud`/`veryLoud`/`VeryLoud`/`VERY_LOUD`.
+///
+/// A variant may accept several strings in [`FromStr`](std::str::FromStr) by listing additional
+/// aliases after the canonical one. The first string is the canonical one returned by
+/// `as_static_str`/`Display` and stored in `ENUM_VARIANT_STRINGS`; every listed string parses
+/// back to the variant. Declaring the same string for two variants is a compile error.
+/// ```
+/// use vlk_tracing_subscriber::stringable_enum;
+///
+/// pub enum Level {
+///     Warn,
+///     Error,
+/// }
+///
+/// stringable_enum! {
+///     Level {
+///         Warn = "warn" | "warning" | "w",
+///         Error = "error" | "err" | "e",
+///     }
+/// }
+///
+/// assert_eq!("warning".parse::<Level>().map(|l| l.as_static_str()), Ok("warn"));
+/// assert_eq!("e".parse::<Level>().map(|l| l.as_static_str()), Ok("error"));
+/// assert_eq!(Level::Warn.as_static_str(), "warn");
+/// assert_eq!(Level::ENUM_VARIANT_STRINGS, ["warn", "error"]);
+/// ```
+///
+/// The `@default Variant` option makes [`FromStr`](std::str::FromStr) resolve any unrecognized
+/// input to a chosen variant instead of returning an error — handy for config values that should
+/// degrade gracefully rather than abort. The fallback must be one of the listed variants:
+/// ```
+/// use vlk_tracing_subscriber::stringable_enum;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum Mode {
+///     Fast,
+///     Safe,
+/// }
+///
+/// stringable_enum! {
+///     @default Safe
+///     Mode {
+///         Fast = "fast",
+///         Safe = "safe",
+///     }
+/// }
+///
+/// assert_eq!("fast".parse::<Mode>(), Ok(Mode::Fast));
+/// assert_eq!("nonsense".parse::<Mode>(), Ok(Mode::Safe));
+/// ```
+///
+/// When no variant matches and there is no `@default`, `from_str` yields a [`ParseEnumError`]
+/// whose message lists the canonical variant strings. The `@ascii-case-insensitive` mode
+/// additionally lets mixed-case input parse:
+/// ```
+/// use vlk_tracing_subscriber::stringable_enum;
+///
+/// #[derive(Debug)]
+/// pub enum Shade {
+///     Light,
+///     Dark,
+/// }
+///
+/// stringable_enum! {
+///     @ascii-case-insensitive
+///     Shade {
+///         Light = "light",
+///         Dark = "dark",
+///     }
+/// }
+///
+/// assert_eq!("DARK".parse::<Shade>().map(|s| s.as_static_str()), Ok("dark"));
+/// let err = "mauve".parse::<Shade>().unwrap_err();
+/// assert_eq!(err.to_string(), "invalid value `mauve`, expected one of: light, dark");
+/// ```
+///
+/// A variant can carry a human-readable description with `{ msg = "..." }`, exposed through
+/// `message()` and the `ENUM_VARIANT_MESSAGES` table (parallel to `ENUM_VARIANT_STRINGS`). This
+/// feeds self-documenting help for clap or TUI pickers without a second hand-maintained table:
+/// ```
+/// use vlk_tracing_subscriber::stringable_enum;
+///
+/// pub enum Speed {
+///     Slow,
+///     Fast,
+/// }
+///
+/// stringable_enum! {
+///     Speed {
+///         Slow = "slow" { msg = "prioritise correctness" },
+///         Fast = "fast",
+///     }
+/// }
+///
+/// assert_eq!(Speed::Slow.message(), Some("prioritise correctness"));
+/// assert_eq!(Speed::Fast.message(), None);
+/// assert_eq!(Speed::ENUM_VARIANT_MESSAGES, [Some("prioritise correctness"), None]);
+/// ```
+///
+/// The `@repr` mode adds integer round-tripping keyed on each variant's 0-based declaration
+/// index, for CLI verbosity counting (`-vv` → index 2) and compact serialization. It requires a
+/// fieldless enum:
+/// ```
+/// use vlk_tracing_subscriber::stringable_enum;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// pub enum Tier {
+///     Low,
+///     Mid,
+///     High,
+/// }
+///
+/// stringable_enum! {
+///     @repr
+///     Tier {
+///         Low = "low",
+///         Mid = "mid",
+///         High = "high",
+///     }
+/// }
+///
+/// assert_eq!(Tier::Mid.to_repr(), 1);
+/// assert_eq!(Tier::from_repr(2), Some(Tier::High));
+/// assert_eq!(Tier::from_repr(3), None);
+/// // out-of-range counts clamp to the last variant
+/// assert_eq!(Tier::from_repr_saturating(99), Tier::High);
+/// ```
 #[macro_export]
 macro_rules! stringable_enum {
-    ($enum:ident { $( $variant:ident = $strval:expr ),+$(,)? }) => {
+    ($enum:ident { $( $variant:ident = $canon:tt $(| $alias:tt)* $({ msg = $msg:tt })? ),+$(,)? }) => {
+        $crate::stringable_enum!(@no-display $enum { $( $variant = $canon $(| $alias)* $({ msg = $msg })? ),+ });
+        $crate::stringable_enum!(@display $enum);
+    };
 
-        $crate::stringable_enum!(@no-display $enum { $( $variant = $strval ),+ });
+    // `@default Variant` makes `from_str` resolve any unmatched input to `Self::Variant` instead
+    // of erroring (mutually exclusive with the `Err` path). The fallback must name one of the
+    // listed unit variants so `as_static_str` stays exhaustive.
+    (@default $fallback:ident @no-display $enum:ident { $( $variant:ident = $canon:tt $(| $alias:tt)* $({ msg = $msg:tt })? ),+$(,)? }) => {
+        $crate::stringable_enum!(@inherent [sensitive] $enum { $( $variant = $canon $(| $alias)* $({ msg = $msg })? ),+ });
+        $crate::stringable_enum!(@from_str [default $fallback] [sensitive] $enum { $( $variant = $canon $(| $alias)* $({ msg = $msg })? ),+ });
+    };
+    (@default $fallback:ident $enum:ident { $( $variant:ident = $canon:tt $(| $alias:tt)* $({ msg = $msg:tt })? ),+$(,)? }) => {
+        $crate::stringable_enum!(@default $fallback @no-display $enum { $( $variant = $canon $(| $alias)* $({ msg = $msg })? ),+ });
+        $crate::stringable_enum!(@display $enum);
+    };
 
-        impl ::core::fmt::Display for $enum {
-            #[inline(always)]
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                f.pad(self.as_static_str())
+    (@rename_all @no-display $case:ident $enum:ident { $($body:tt)* }) => {
+        $crate::stringable_enum!(@rn [$crate::__rename_case!($case)] [@no-display] $enum [] { $($body)* });
+    };
+
+    (@rename_all $case:ident $enum:ident { $($body:tt)* }) => {
+        $crate::stringable_enum!(@rn [$crate::__rename_case!($case)] [] $enum [] { $($body)* });
+    };
+
+    // Munch the `@rename_all` variant list into the manual form, generating a string for every
+    // bare variant while leaving explicit `Variant = "..."` overrides untouched.
+    (@rn [$case:expr] [$($disp:tt)*] $enum:ident [$($acc:tt)*] { }) => {
+        $crate::stringable_enum!( $($disp)* $enum { $($acc)* } );
+    };
+    (@rn [$case:expr] [$($disp:tt)*] $enum:ident [$($acc:tt)*] { $variant:ident = $strval:expr , $($rest:tt)* }) => {
+        $crate::stringable_enum!(@rn [$case] [$($disp)*] $enum [$($acc)* $variant = $strval,] { $($rest)* });
+    };
+    (@rn [$case:expr] [$($disp:tt)*] $enum:ident [$($acc:tt)*] { $variant:ident = $strval:expr }) => {
+        $crate::stringable_enum!(@rn [$case] [$($disp)*] $enum [$($acc)* $variant = $strval,] { });
+    };
+    (@rn [$case:expr] [$($disp:tt)*] $enum:ident [$($acc:tt)*] { $variant:ident , $($rest:tt)* }) => {
+        $crate::stringable_enum!(@rn [$case] [$($disp)*] $enum
+            [$($acc)* $variant = { $crate::__rename_const!($case, ::core::stringify!($variant)) },] { $($rest)* });
+    };
+    (@rn [$case:expr] [$($disp:tt)*] $enum:ident [$($acc:tt)*] { $variant:ident }) => {
+        $crate::stringable_enum!(@rn [$case] [$($disp)*] $enum
+            [$($acc)* $variant = { $crate::__rename_const!($case, ::core::stringify!($variant)) },] { });
+    };
+
+    (@no-display $enum:ident { $( $variant:ident = $canon:tt $(| $alias:tt)* $({ msg = $msg:tt })? ),+$(,)? }) => {
+        $crate::stringable_enum!(@inherent [sensitive] $enum { $( $variant = $canon $(| $alias)* $({ msg = $msg })? ),+ });
+        $crate::stringable_enum!(@from_str [err] [sensitive] $enum { $( $variant = $canon $(| $alias)* $({ msg = $msg })? ),+ });
+    };
+
+    // `@ascii-case-insensitive` lowercases the comparison so `WARN`, `Warn`, and `warn` all parse
+    // to the same variant — useful for log levels and color flags passed on the CLI or via env.
+    (@ascii-case-insensitive @no-display $enum:ident { $( $variant:ident = $canon:tt $(| $alias:tt)* $({ msg = $msg:tt })? ),+$(,)? }) => {
+        $crate::stringable_enum!(@inherent [insensitive] $enum { $( $variant = $canon $(| $alias)* $({ msg = $msg })? ),+ });
+        $crate::stringable_enum!(@from_str [err] [insensitive] $enum { $( $variant = $canon $(| $alias)* $({ msg = $msg })? ),+ });
+    };
+    (@ascii-case-insensitive $enum:ident { $( $variant:ident = $canon:tt $(| $alias:tt)* $({ msg = $msg:tt })? ),+$(,)? }) => {
+        $crate::stringable_enum!(@ascii-case-insensitive @no-display $enum { $( $variant = $canon $(| $alias)* $({ msg = $msg })? ),+ });
+        $crate::stringable_enum!(@display $enum);
+    };
+
+    // `@repr` additionally generates integer round-tripping based on each variant's 0-based
+    // declaration index. The index is independent of any explicit discriminant, so `to_repr` and
+    // `from_repr` always agree.
+    (@repr @no-display $enum:ident { $( $variant:ident = $canon:tt $(| $alias:tt)* $({ msg = $msg:tt })? ),+$(,)? }) => {
+        $crate::stringable_enum!(@no-display $enum { $( $variant = $canon $(| $alias)* $({ msg = $msg })? ),+ });
+        $crate::stringable_enum!(@repr-impl $enum { $($variant)+ });
+    };
+    (@repr $enum:ident { $( $variant:ident = $canon:tt $(| $alias:tt)* $({ msg = $msg:tt })? ),+$(,)? }) => {
+        $crate::stringable_enum!($enum { $( $variant = $canon $(| $alias)* $({ msg = $msg })? ),+ });
+        $crate::stringable_enum!(@repr-impl $enum { $($variant)+ });
+    };
+    (@repr-impl $enum:ident { $($variant:ident)+ }) => {
+        impl $enum {
+            /// This variant's 0-based index in declaration order.
+            pub const fn to_repr(&self) -> usize {
+                $crate::stringable_enum!(@repr-to self [0usize] { $($variant)+ })
             }
-        }
 
+            /// The variant at 0-based declaration index `n`, or `None` if out of range.
+            pub const fn from_repr(n: usize) -> ::core::option::Option<Self> {
+                $crate::stringable_enum!(@repr-from n [0usize] { $($variant)+ })
+            }
+
+            /// Like [`from_repr`](Self::from_repr) but clamps out-of-range indices to the last
+            /// variant instead of returning `None`.
+            pub const fn from_repr_saturating(n: usize) -> Self {
+                let idx = if n >= Self::NUM_VARIANTS {
+                    Self::NUM_VARIANTS - 1
+                } else {
+                    n
+                };
+                match Self::from_repr(idx) {
+                    ::core::option::Option::Some(variant) => variant,
+                    ::core::option::Option::None => ::core::unreachable!(),
+                }
+            }
+        }
+    };
+    // Expand to a nested `match` mapping `$s` to the declaration index of its variant.
+    (@repr-to $s:ident [$idx:expr] { }) => {
+        ::core::unreachable!()
     };
+    (@repr-to $s:ident [$idx:expr] { $first:ident $($rest:ident)* }) => {
+        match $s {
+            Self::$first => $idx,
+            _ => $crate::stringable_enum!(@repr-to $s [$idx + 1usize] { $($rest)* }),
+        }
+    };
+    // Expand to an `if`/`else` chain mapping `$n` to the variant at each index.
+    (@repr-from $n:ident [$idx:expr] { }) => {
+        ::core::option::Option::None
+    };
+    (@repr-from $n:ident [$idx:expr] { $first:ident $($rest:ident)* }) => {
+        if $n == $idx {
+            ::core::option::Option::Some(Self::$first)
+        } else {
+            $crate::stringable_enum!(@repr-from $n [$idx + 1usize] { $($rest)* })
+        }
+    };
+
+    // The inherent `impl` block shared by every form: the variant tables and `as_static_str`.
+    // `$ci` (`sensitive`/`insensitive`) selects how the uniqueness check compares strings so it
+    // matches the generated `from_str`.
+    (@inherent [$ci:ident] $enum:ident { $( $variant:ident = $canon:tt $(| $alias:tt)* $({ msg = $msg:tt })? ),+$(,)? }) => {
+        // Every canonical string and alias across all variants must be distinct, otherwise a
+        // string would parse ambiguously. A duplicate trips this const evaluation at compile time.
+        const _: () = $crate::__assert_unique_strs(&[ $( $canon $(, $alias)* ),+ ], $crate::__is_insensitive!($ci));
 
-    (@no-display $enum:ident { $( $variant:ident = $strval:expr ),+$(,)? }) => {
         impl $enum {
             pub const NUM_VARIANTS: usize = *&[$(Self::$variant),+].len();
             pub const ENUM_VARIANTS: [Self; Self::NUM_VARIANTS] = [$(Self::$variant),+];
-            pub const ENUM_VARIANT_STRINGS: [&'static str; Self::NUM_VARIANTS] = [$($strval),+];
+            pub const ENUM_VARIANT_STRINGS: [&'static str; Self::NUM_VARIANTS] = [$($canon),+];
+            /// The optional human-readable description for each variant, parallel to
+            /// [`ENUM_VARIANTS`](Self::ENUM_VARIANTS) and
+            /// [`ENUM_VARIANT_STRINGS`](Self::ENUM_VARIANT_STRINGS).
+            pub const ENUM_VARIANT_MESSAGES: [::core::option::Option<&'static str>; Self::NUM_VARIANTS] =
+                [$( $crate::__opt_str!($($msg)?) ),+];
 
             pub const fn as_static_str(&self) -> &'static str {
                 match self {
-                    $(Self::$variant => $strval,)+
+                    $(Self::$variant => $canon,)+
+                }
+            }
+
+            /// The human-readable description attached to this variant, if one was given.
+            pub const fn message(&self) -> ::core::option::Option<&'static str> {
+                match self {
+                    $(Self::$variant => $crate::__opt_str!($($msg)?),)+
                 }
             }
         }
+    };
+
+    // The `FromStr` impl, parameterized by what happens when no string matches (`err` produces a
+    // `ParseEnumError`, `default V` resolves to `Self::V`) and by whether matching is
+    // ASCII-case-`sensitive` or case-`insensitive`. The two arms are kept separate so the unmatched
+    // branch is expanded in the same context as the `s` binding it refers to.
+    (@from_str [err] [$ci:ident] $enum:ident { $( $variant:ident = $canon:tt $(| $alias:tt)* $({ msg = $msg:tt })? ),+$(,)? }) => {
         impl ::std::str::FromStr for $enum {
-            type Err = ();
+            type Err = $crate::ParseEnumError<Self>;
             fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
-                match s {
-                    $( $strval => Ok(Self::$variant), )+
-                    _ => Err(()),
-                }
+                $( if $crate::__str_match!($ci, s, $canon) $(|| $crate::__str_match!($ci, s, $alias))* {
+                    return ::core::result::Result::Ok(Self::$variant);
+                } )+
+                ::core::result::Result::Err($crate::ParseEnumError::new(s, &Self::ENUM_VARIANT_STRINGS))
+            }
+        }
+    };
+    (@from_str [default $fallback:ident] [$ci:ident] $enum:ident { $( $variant:ident = $canon:tt $(| $alias:tt)* $({ msg = $msg:tt })? ),+$(,)? }) => {
+        impl ::std::str::FromStr for $enum {
+            type Err = $crate::ParseEnumError<Self>;
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                $( if $crate::__str_match!($ci, s, $canon) $(|| $crate::__str_match!($ci, s, $alias))* {
+                    return ::core::result::Result::Ok(Self::$variant);
+                } )+
+                ::core::result::Result::Ok(Self::$fallback)
             }
         }
     };
+
+    (@display $enum:ident) => {
+        impl ::core::fmt::Display for $enum {
+            #[inline(always)]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.pad(self.as_static_str())
+            }
+        }
+    };
+}
+
+/// Compare the input string `$s` against a variant string `$v`, either case-`sensitive` or
+/// case-`insensitive`. Implementation detail of [`stringable_enum!`]'s generated `from_str`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __str_match {
+    (sensitive, $s:expr, $v:expr) => {
+        $s == $v
+    };
+    (insensitive, $s:expr, $v:expr) => {
+        $s.eq_ignore_ascii_case($v)
+    };
+}
+
+/// Map the `sensitive`/`insensitive` keyword to a `bool` for [`__assert_unique_strs`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __is_insensitive {
+    (sensitive) => {
+        false
+    };
+    (insensitive) => {
+        true
+    };
+}
+
+/// Wrap an optional per-variant message into `Some(..)`, or `None` when absent. Implementation
+/// detail of [`stringable_enum!`]'s `{ msg = "..." }` metadata.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __opt_str {
+    () => {
+        ::core::option::Option::None
+    };
+    ($msg:expr) => {
+        ::core::option::Option::Some($msg)
+    };
+}
+
+/// The error returned by the [`FromStr`](std::str::FromStr) impl that [`stringable_enum!`]
+/// generates, in place of the uninformative `()`. Its [`Display`](core::fmt::Display) reports the
+/// offending input alongside the list of canonical variant strings, so it composes with `?` and
+/// `anyhow`. The enum type parameter keeps the error distinct per enum.
+pub struct ParseEnumError<E> {
+    input: ::std::string::String,
+    expected: &'static [&'static str],
+    _marker: ::core::marker::PhantomData<fn() -> E>,
+}
+
+impl<E> ParseEnumError<E> {
+    /// Build the error from the input that failed to parse and the enum's accepted strings.
+    #[doc(hidden)]
+    pub fn new(input: &str, expected: &'static [&'static str]) -> Self {
+        Self {
+            input: input.to_owned(),
+            expected,
+            _marker: ::core::marker::PhantomData,
+        }
+    }
+
+    /// The input string that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The variant strings that would have parsed successfully.
+    pub fn expected(&self) -> &'static [&'static str] {
+        self.expected
+    }
+}
+
+impl<E> ::core::fmt::Display for ParseEnumError<E> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "invalid value `{}`, expected one of: ", self.input)?;
+        let mut first = true;
+        for value in self.expected {
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+            f.write_str(value)?;
+        }
+        Ok(())
+    }
+}
+
+// Hand-written so the enum type parameter `E` does not need to be `Debug`/`PartialEq` itself.
+impl<E> ::core::fmt::Debug for ParseEnumError<E> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("ParseEnumError")
+            .field("input", &self.input)
+            .field("expected", &self.expected)
+            .finish()
+    }
+}
+
+impl<E> PartialEq for ParseEnumError<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.input == other.input && self.expected == other.expected
+    }
+}
+
+impl<E> Eq for ParseEnumError<E> {}
+
+impl<E> ::std::error::Error for ParseEnumError<E> {}
+
+/// Map a `rename_all` case keyword to its [`RenameCase`]. Implementation detail of
+/// [`stringable_enum!`]'s `@rename_all` mode.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rename_case {
+    (snake_case) => {
+        $crate::RenameCase::Snake
+    };
+    (kebab_case) => {
+        $crate::RenameCase::Kebab
+    };
+    (camelCase) => {
+        $crate::RenameCase::Camel
+    };
+    (PascalCase) => {
+        $crate::RenameCase::Pascal
+    };
+    (SCREAMING_SNAKE_CASE) => {
+        $crate::RenameCase::Screaming
+    };
+}
+
+/// Convert the string literal `$id` to `$case` at compile time, yielding a `&'static str`.
+/// Implementation detail of [`stringable_enum!`]'s `@rename_all` mode.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rename_const {
+    ($case:expr, $id:expr) => {{
+        const ID: &str = $id;
+        const LEN: usize = $crate::__rename_len(ID, $case);
+        const BUF: [u8; LEN] = $crate::__rename_buf::<LEN>(ID, $case);
+        match ::core::str::from_utf8(&BUF) {
+            ::core::result::Result::Ok(s) => s,
+            ::core::result::Result::Err(_) => ::core::unreachable!(),
+        }
+    }};
+}
+
+/// A target case for [`stringable_enum!`]'s `@rename_all` mode.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameCase {
+    Snake,
+    Kebab,
+    Camel,
+    Pascal,
+    Screaming,
+}
+
+const fn __to_lower(c: u8) -> u8 {
+    if c.is_ascii_uppercase() {
+        c + (b'a' - b'A')
+    } else {
+        c
+    }
+}
+
+const fn __to_upper(c: u8) -> u8 {
+    if c.is_ascii_lowercase() {
+        c - (b'a' - b'A')
+    } else {
+        c
+    }
+}
+
+const fn __str_eq(a: &str, b: &str, case_insensitive: bool) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        let (ca, cb) = if case_insensitive {
+            (__to_lower(a[i]), __to_lower(b[i]))
+        } else {
+            (a[i], b[i])
+        };
+        if ca != cb {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Panic during const evaluation if any two of `strings` are equal. Used by
+/// [`stringable_enum!`] to reject two variants that declare the same canonical string or alias.
+/// When `case_insensitive` is set, strings that differ only in ASCII case also count as equal,
+/// matching the `@ascii-case-insensitive` parse behaviour.
+#[doc(hidden)]
+pub const fn __assert_unique_strs(strings: &[&str], case_insensitive: bool) {
+    let mut i = 0;
+    while i < strings.len() {
+        let mut j = i + 1;
+        while j < strings.len() {
+            if __str_eq(strings[i], strings[j], case_insensitive) {
+                ::core::panic!("stringable_enum!: duplicate variant string or alias");
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+/// The number of bytes [`__rename_buf`] will produce for `id` in `case`. Kept in lockstep with
+/// `__rename_buf` so the generated `[u8; N]` is exactly filled.
+#[doc(hidden)]
+pub const fn __rename_len(id: &str, case: RenameCase) -> usize {
+    let bytes = id.as_bytes();
+    let mut i = 0;
+    let mut out_len = 0;
+    let mut seg_start = true;
+    let mut prev_lower_or_digit = false;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c == b'_' {
+            seg_start = true;
+            prev_lower_or_digit = false;
+            i += 1;
+            continue;
+        }
+        let new_seg = seg_start || (c.is_ascii_uppercase() && prev_lower_or_digit);
+        match case {
+            RenameCase::Snake | RenameCase::Kebab | RenameCase::Screaming => {
+                if new_seg && out_len > 0 {
+                    out_len += 1;
+                }
+                out_len += 1;
+            }
+            RenameCase::Camel | RenameCase::Pascal => {
+                out_len += 1;
+            }
+        }
+        seg_start = false;
+        prev_lower_or_digit = c.is_ascii_lowercase() || c.is_ascii_digit();
+        i += 1;
+    }
+    out_len
+}
+
+/// Convert `id` to `case`, writing exactly `N` bytes (see [`__rename_len`]).
+#[doc(hidden)]
+pub const fn __rename_buf<const N: usize>(id: &str, case: RenameCase) -> [u8; N] {
+    let bytes = id.as_bytes();
+    let mut out = [0u8; N];
+    let mut i = 0;
+    let mut oi = 0;
+    let mut seg_start = true;
+    let mut prev_lower_or_digit = false;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c == b'_' {
+            seg_start = true;
+            prev_lower_or_digit = false;
+            i += 1;
+            continue;
+        }
+        let new_seg = seg_start || (c.is_ascii_uppercase() && prev_lower_or_digit);
+        match case {
+            RenameCase::Snake | RenameCase::Screaming => {
+                if new_seg && oi > 0 {
+                    out[oi] = b'_';
+                    oi += 1;
+                }
+                out[oi] = if matches!(case, RenameCase::Screaming) {
+                    __to_upper(c)
+                } else {
+                    __to_lower(c)
+                };
+                oi += 1;
+            }
+            RenameCase::Kebab => {
+                if new_seg && oi > 0 {
+                    out[oi] = b'-';
+                    oi += 1;
+                }
+                out[oi] = __to_lower(c);
+                oi += 1;
+            }
+            RenameCase::Camel | RenameCase::Pascal => {
+                out[oi] = if new_seg {
+                    if oi == 0 && matches!(case, RenameCase::Camel) {
+                        __to_lower(c)
+                    } else {
+                        __to_upper(c)
+                    }
+                } else {
+                    __to_lower(c)
+                };
+                oi += 1;
+            }
+        }
+        seg_start = false;
+        prev_lower_or_digit = c.is_ascii_lowercase() || c.is_ascii_digit();
+        i += 1;
+    }
+    out
 }
 
 #[cfg(feature = "ansi")]
@@ -160,6 +774,7 @@ impl LogLevelSerdable {
 
 #[cfg(feature = "serde")]
 stringable_enum! {
+    @repr
     LogLevelSerdable {
         Trace = "trace",
         Debug = "debug",